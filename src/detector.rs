@@ -1,6 +1,21 @@
 use std::ffi::OsStr;
 
-use crate::TextReader;
+use regex::Regex;
+
+/// The character-stream operations `Detector` needs from a reader. Implemented
+/// by both `TextReader` and `StreamReader` so a `Detector` can do lookahead
+/// matching (including `next_regex`) against either one.
+pub trait CharSource {
+  /// Next character, advancing past it.
+  fn next(&mut self) -> Option<char>;
+  /// Back to the previous character. Returns `false` if the reader could
+  /// not rewind any further (e.g. a `StreamReader` past its rewind window).
+  fn back(&mut self) -> bool;
+  /// Whether another character is available.
+  fn has_next(&mut self) -> bool;
+  /// The remaining not-yet-consumed text, for anchored regex matching.
+  fn remaining_text(&mut self) -> String;
+}
 
 /// Text reader detector
 ///
@@ -35,13 +50,16 @@ use crate::TextReader;
 /// println!("{}", ret); // "ttypeA"
 /// ```
 #[derive(Debug)]
-pub struct Detector<'a> {
-  reader: &'a mut TextReader,
+pub struct Detector<'a, R: CharSource> {
+  reader: &'a mut R,
   compares: Vec<char>,
   last_len: usize,
+  regex_match: Option<String>,
+  regex_groups: Vec<Option<String>>,
+  regex_result: Option<bool>,
 }
 
-impl<'a> Detector<'a> {
+impl<'a, R: CharSource> Detector<'a, R> {
 
   /// Create detector
   ///
@@ -52,8 +70,75 @@ impl<'a> Detector<'a> {
   ///
   /// let mut reader = TextReader::new("abc");
   /// ```
-  pub fn new(reader: &'a mut TextReader) -> Self {
-    Self { reader, compares: Vec::new(), last_len: 0 }
+  pub fn new(reader: &'a mut R) -> Self {
+    Self {
+      reader,
+      compares: Vec::new(),
+      last_len: 0,
+      regex_match: None,
+      regex_groups: Vec::new(),
+      regex_result: None,
+    }
+  }
+
+  /// Detect a regex pattern anchored at the current position.
+  /// On success the matched span is consumed (so `rollback()` still works)
+  /// and the match/capture groups are recorded for `matched()`/`group()`.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use text_reader::TextReader;
+  ///
+  /// let mut reader = TextReader::new("123abc");
+  /// let mut detector = reader.detector();
+  /// if detector.next_regex(r"\d+").yes() {
+  ///   println!("{:?}", detector.matched()); // Some("123")
+  /// }
+  /// ```
+  pub fn next_regex(&mut self, pattern: &str) -> &mut Self {
+    self.regex_match = None;
+    self.regex_groups = Vec::new();
+    self.regex_result = Some(false);
+
+    let regex = match Regex::new(&format!("^(?:{})", pattern)) {
+      Ok(re) => re,
+      Err(_) => return self,
+    };
+
+    let tail = self.reader.remaining_text();
+    if let Some(caps) = regex.captures(&tail) {
+      let whole = caps.get(0).unwrap();
+      let matched_len = whole.as_str().chars().count();
+
+      for _ in 0..matched_len {
+        self.reader.next();
+      }
+
+      self.last_len = matched_len;
+      self.regex_groups = (1..caps.len())
+        .map(|ix| caps.get(ix).map(|m| m.as_str().to_string()))
+        .collect();
+      self.regex_match = Some(whole.as_str().to_string());
+      self.regex_result = Some(true);
+    }
+
+    self
+  }
+
+  /// The text matched by the most recent `next_regex()` call, if it matched.
+  pub fn matched(&self) -> Option<&str> {
+    self.regex_match.as_deref()
+  }
+
+  /// A capture group (1-indexed, like the `regex` crate's group 0 being the
+  /// whole match) from the most recent `next_regex()` call. Returns `None`
+  /// for `0` or any index past the last captured group, rather than
+  /// panicking.
+  pub fn group(&self, ix: usize) -> Option<&str> {
+    ix.checked_sub(1)
+      .and_then(|ix| self.regex_groups.get(ix))
+      .and_then(|g| g.as_deref())
   }
 
   /// Detect next char
@@ -126,6 +211,10 @@ impl<'a> Detector<'a> {
   /// Rollback detector
   /// If detect success detector not back position. if want, use rollback function to reset reader position
   ///
+  /// Panics if the reader cannot rewind the full matched distance (e.g. a
+  /// `StreamReader` whose `rewind_window` is smaller than the match) rather
+  /// than leaving the reader desynced at a partially-restored position.
+  ///
   /// # Examples
   ///
   /// ```rust
@@ -150,13 +239,14 @@ impl<'a> Detector<'a> {
   /// println!("{}", vec.iter().collect::<String>()); // abc
   /// ```
   pub fn rollback(&mut self) -> &mut Self {
-    for _ in 0..self.last_len {
-      self.reader.back();
-    }
-    self
+    self.restore(self.last_len)
   }
 
   fn detect(&mut self) -> bool {
+    if let Some(result) = self.regex_result.take() {
+      return result;
+    }
+
     let mut ix = 0;
     let len = self.compares.len();
 
@@ -193,13 +283,37 @@ impl<'a> Detector<'a> {
   }
 
   fn restore(&mut self, count: usize) -> &mut Self {
-    if count == 0 {
-      return self;
-    }
     for _ in 0..count {
-      self.reader.back();
+      assert!(
+        self.reader.back(),
+        "Detector could not rewind the full matched distance; the reader's \
+         rewind window must be at least as large as the longest match a \
+         Detector on it will need to undo"
+      );
     }
     self
   }
 }
 
+impl CharSource for crate::TextReader {
+  fn next(&mut self) -> Option<char> {
+    crate::TextReader::next(self)
+  }
+
+  fn back(&mut self) -> bool {
+    if self.position() == 0 {
+      return false;
+    }
+    crate::TextReader::back(self);
+    true
+  }
+
+  fn has_next(&mut self) -> bool {
+    crate::TextReader::has_next(self)
+  }
+
+  fn remaining_text(&mut self) -> String {
+    crate::TextReader::remaining_text(self)
+  }
+}
+
@@ -0,0 +1,280 @@
+use std::ffi::OsStr;
+use unicode_segmentation::UnicodeSegmentation;
+
+#[derive(Debug)]
+pub struct GraphemeReader {
+  len: usize,
+  clusters: Vec<String>,
+  position: usize,
+  line: usize,
+  cursor: isize,
+}
+
+///
+/// Grapheme-cluster aware text reader.
+///
+/// `TextReader` walks `char`s (Unicode scalar values), so a user-perceived character
+/// built from several scalar values - an accented letter with a combining mark, a flag
+/// emoji - is split across several `next()` calls. `GraphemeReader` walks extended
+/// grapheme clusters instead, so `next()`/`back()`/`cursor()` all count what a user
+/// actually sees.
+///
+/// # Examples
+/// ```
+/// use text_reader::GraphemeReader;
+/// let mut reader = GraphemeReader::new("e\u{301}\ndef");
+/// assert_eq!(Some("e\u{301}".to_string()), reader.next());
+/// assert_eq!(1, reader.cursor());
+/// ```
+impl GraphemeReader {
+  pub fn new<S: AsRef<OsStr>>(text: S) -> GraphemeReader {
+    let clusters: Vec<String> = text.as_ref().to_str().unwrap()
+      .graphemes(true)
+      .map(|g| g.to_string())
+      .collect();
+    let len = clusters.len();
+    GraphemeReader {
+      clusters,
+      position: 0,
+      line: 1,
+      len,
+      cursor: 0,
+    }
+  }
+
+  ///
+  /// Reset to first grapheme cluster
+  ///
+  /// # Examples
+  /// ```
+  /// use text_reader::GraphemeReader;
+  /// let mut reader = GraphemeReader::new("abc\ndef");
+  /// let reader = reader.reset();
+  /// ```
+  pub fn reset(&mut self) -> &mut GraphemeReader {
+    self.line = 1;
+    self.position = 0;
+    self.cursor = 0;
+    self
+  }
+
+  ///
+  /// Peek current grapheme cluster
+  ///
+  /// # Examples
+  /// ```
+  /// use text_reader::GraphemeReader;
+  /// let mut reader = GraphemeReader::new("abc\ndef");
+  /// let cluster = reader.peek();
+  /// ```
+  pub fn peek(&self) -> Option<String> {
+    if self.position == 0 {
+      return None;
+    }
+    if self.position > self.len {
+      return None;
+    }
+    self.clusters.get(self.position - 1).cloned()
+  }
+
+  ///
+  /// Next grapheme cluster
+  ///
+  /// # Examples
+  /// ```
+  /// use text_reader::GraphemeReader;
+  /// let mut reader = GraphemeReader::new("abc\ndef");
+  /// let cluster = reader.next();
+  /// ```
+  // Mirrors TextReader's own next()/back() naming; not a std Iterator.
+  #[allow(clippy::should_implement_trait)]
+  pub fn next(&mut self) -> Option<String> {
+    if !self.has_next() {
+      return None;
+    }
+    let cluster = self.clusters.get(self.position).unwrap().clone();
+    self.position += 1;
+    self.cursor += 1;
+
+    if cluster == "\n" {
+      self.line += 1;
+      self.cursor = 0;
+    }
+
+    Some(cluster)
+  }
+
+  /// Position in grapheme clusters
+  /// # Examples
+  /// ```
+  /// use text_reader::GraphemeReader;
+  /// let mut reader = GraphemeReader::new("abc\ndef");
+  /// let position = reader.position();
+  /// ```
+  pub fn position(&self) -> usize {
+    self.position
+  }
+
+  /// Current text line number
+  /// # Examples
+  /// ```
+  /// use text_reader::GraphemeReader;
+  /// let mut reader = GraphemeReader::new("abc\ndef");
+  /// let line = reader.line();
+  /// ```
+  pub fn line(&self) -> usize {
+    self.line
+  }
+
+  /// Current line position, in grapheme clusters
+  /// # Examples
+  /// ```
+  /// use text_reader::GraphemeReader;
+  /// let mut reader = GraphemeReader::new("abc\ndef");
+  /// reader.next();
+  /// reader.next();
+  /// let cursor = reader.cursor();
+  /// println!("CURSOR: {}", cursor); // 2
+  /// ```
+  pub fn cursor(&self) -> usize {
+    self.cursor as usize
+  }
+
+  /// Text length, in grapheme clusters
+  /// # Examples
+  /// ```
+  /// use text_reader::GraphemeReader;
+  /// let reader = GraphemeReader::new("abc\ndef");
+  /// let len = reader.len();
+  /// ```
+  pub fn len(&self) -> usize {
+    self.len
+  }
+
+  /// Whether the text has no grapheme clusters at all
+  /// # Examples
+  /// ```
+  /// use text_reader::GraphemeReader;
+  /// let reader = GraphemeReader::new("abc\ndef");
+  /// assert_eq!(false, reader.is_empty());
+  /// ```
+  pub fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
+  ///
+  /// Back to previous grapheme cluster
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use text_reader::GraphemeReader;
+  /// let mut reader = GraphemeReader::new("abc\ndef");
+  /// reader.back();
+  /// ```
+  ///
+  pub fn back(&mut self) -> &mut GraphemeReader {
+    if self.position == 0 {
+      return self;
+    }
+
+    match self.peek() {
+      None => return self,
+      Some(cluster) => {
+        if cluster != "\n" {
+          self.position -= 1;
+          self.cursor -= 1;
+          return self;
+        }
+      }
+    }
+
+    self.position -= 1;
+    self.line -= 1;
+
+    let position = self.position;
+    let line = self.line;
+
+    let mut distance = 0;
+    loop {
+      match self.back().peek() {
+        None => break,
+        Some(ref cluster) if cluster == "\n" => break,
+        _ => distance += 1,
+      }
+    }
+
+    self.position = position;
+    self.line = line;
+    self.cursor = distance + 1;
+    self
+  }
+
+  ///
+  /// Current line string
+  ///
+  /// # Examples
+  /// ```
+  /// use text_reader::GraphemeReader;
+  /// let mut reader = GraphemeReader::new("abc\ndef");
+  /// let line_text = reader.this_line();
+  /// ```
+  pub fn this_line(&mut self) -> Option<String> {
+    let position = self.position;
+    let cursor = self.cursor;
+    let line = self.line;
+
+    loop {
+      if self.position == 0 || self.cursor == 0 {
+        break;
+      }
+
+      match self.back().peek() {
+        Some(ref cluster) if cluster == "\n" => break,
+        _ => continue,
+      }
+    }
+
+    let start_position = self.position;
+
+    while self.has_next() {
+      match self.next() {
+        Some(ref cluster) if cluster == "\n" => {
+          self.back();
+          break;
+        }
+        _ => continue,
+      }
+    }
+
+    if start_position == self.position {
+      return None;
+    }
+
+    let line_text = self.clusters.iter().enumerate()
+      .filter(|(ix, _cluster)| *ix >= start_position && *ix < self.position)
+      .map(|(_ix, cluster)| cluster.as_str())
+      .collect();
+
+    self.position = position;
+    self.cursor = cursor;
+    self.line = line;
+
+    Some(line_text)
+  }
+
+  ///
+  /// Has next grapheme cluster
+  ///
+  /// # Examples
+  /// ```
+  /// use text_reader::GraphemeReader;
+  /// let mut reader = GraphemeReader::new("abc\ndef");
+  /// while reader.has_next() {
+  ///    let cluster = reader.next();
+  /// }
+  /// ```
+  pub fn has_next(&self) -> bool {
+    self.position < self.len
+  }
+}
@@ -0,0 +1,9 @@
+mod textreader;
+mod detector;
+mod graphemereader;
+mod streamreader;
+
+pub use textreader::{TextReader, SeekFrom, WordStyle};
+pub use detector::{Detector, CharSource};
+pub use graphemereader::GraphemeReader;
+pub use streamreader::{StreamReader, RewindWindowExceeded, DEFAULT_REWIND_WINDOW};
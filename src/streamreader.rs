@@ -0,0 +1,352 @@
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+use std::io::Read;
+
+use crate::{CharSource, Detector};
+
+/// Default number of characters retained for `back()`, matching the
+/// ballpark of rustyline's `MAX_LINE` history buffer.
+pub const DEFAULT_REWIND_WINDOW: usize = 4 * 1024;
+
+/// Returned by `StreamReader::back()` when the caller tries to rewind
+/// past the retained window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RewindWindowExceeded;
+
+impl fmt::Display for RewindWindowExceeded {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "cannot rewind past the retained window")
+  }
+}
+
+impl Error for RewindWindowExceeded {}
+
+///
+/// Streaming text reader built on top of `io::Read`.
+///
+/// Unlike `TextReader`, which eagerly decodes the whole input into a `Vec<char>`,
+/// `StreamReader` decodes UTF-8 incrementally and only retains the last
+/// `rewind_window` characters, so `back()` only works within that trailing
+/// window. This makes it usable for large or piped inputs that should not be
+/// fully buffered in memory.
+///
+/// # Examples
+/// ```
+/// use text_reader::StreamReader;
+/// let mut reader = StreamReader::from_reader("abc\ndef".as_bytes());
+/// while reader.has_next() {
+///   println!("{:?}", reader.next());
+/// }
+/// ```
+#[derive(Debug)]
+pub struct StreamReader<R> {
+  reader: R,
+  pending: Vec<u8>,
+  lookahead: VecDeque<char>,
+  history: VecDeque<char>,
+  rewind_window: usize,
+  position: usize,
+  line: usize,
+  cursor: isize,
+  bytes_seen: usize,
+  exhausted: bool,
+}
+
+impl<R: Read> StreamReader<R> {
+  /// Wrap a reader with the default rewind window.
+  pub fn from_reader(reader: R) -> StreamReader<R> {
+    StreamReader::with_rewind_window(reader, DEFAULT_REWIND_WINDOW)
+  }
+
+  /// Wrap a reader, retaining `rewind_window` characters for `back()`.
+  ///
+  /// # Examples
+  /// ```
+  /// use text_reader::StreamReader;
+  /// let mut reader = StreamReader::with_rewind_window("abc\ndef".as_bytes(), 2);
+  /// ```
+  pub fn with_rewind_window(reader: R, rewind_window: usize) -> StreamReader<R> {
+    StreamReader {
+      reader,
+      pending: Vec::new(),
+      lookahead: VecDeque::new(),
+      history: VecDeque::new(),
+      rewind_window,
+      position: 0,
+      line: 1,
+      cursor: 0,
+      bytes_seen: 0,
+      exhausted: false,
+    }
+  }
+
+  fn fill_lookahead(&mut self) {
+    if !self.lookahead.is_empty() || self.exhausted {
+      return;
+    }
+
+    let mut buf = [0u8; 4096];
+    loop {
+      match self.reader.read(&mut buf) {
+        Ok(0) => {
+          self.pending.clear();
+          self.exhausted = true;
+          return;
+        }
+        Ok(n) => {
+          self.pending.extend_from_slice(&buf[..n]);
+          if self.decode_pending() {
+            return;
+          }
+        }
+        Err(_) => {
+          self.exhausted = true;
+          return;
+        }
+      }
+    }
+  }
+
+  fn decode_pending(&mut self) -> bool {
+    let valid_len = match std::str::from_utf8(&self.pending) {
+      Ok(s) => {
+        self.bytes_seen += s.len();
+        self.lookahead.extend(s.chars());
+        self.pending.len()
+      }
+      Err(e) => {
+        let valid_len = e.valid_up_to();
+        if valid_len > 0 {
+          let s = std::str::from_utf8(&self.pending[..valid_len]).unwrap();
+          self.bytes_seen += s.len();
+          self.lookahead.extend(s.chars());
+        }
+        valid_len
+      }
+    };
+    self.pending.drain(..valid_len);
+    !self.lookahead.is_empty()
+  }
+
+  /// Read through to the end of the underlying reader, returning the
+  /// not-yet-consumed text. Used for `Detector`/`next_regex()` lookahead,
+  /// which needs to see past what a single `fill_lookahead()` pulls in.
+  /// Unlike `next()`/`back()`, this gives up the streaming memory guarantee
+  /// for whatever remains of the input.
+  fn remaining_text(&mut self) -> String {
+    let mut buf = [0u8; 4096];
+    while !self.exhausted {
+      match self.reader.read(&mut buf) {
+        Ok(0) => {
+          self.pending.clear();
+          self.exhausted = true;
+        }
+        Ok(n) => {
+          self.pending.extend_from_slice(&buf[..n]);
+          self.decode_pending();
+        }
+        Err(_) => {
+          self.exhausted = true;
+        }
+      }
+    }
+    self.lookahead.iter().collect()
+  }
+
+  ///
+  /// Detect possible strings/patterns, same as `TextReader::detector()`.
+  /// Matching via `next_regex()` reads the rest of the underlying reader
+  /// up front (see `remaining_text`), so it forfeits bounded memory use.
+  ///
+  /// # Examples
+  /// ```
+  /// use text_reader::StreamReader;
+  /// let mut reader = StreamReader::from_reader("123abc".as_bytes());
+  /// let mut detector = reader.detector();
+  /// assert_eq!(true, detector.next_regex(r"\d+").yes());
+  /// ```
+  pub fn detector(&mut self) -> Detector<'_, StreamReader<R>> {
+    Detector::new(self)
+  }
+
+  fn push_history(&mut self, ch: char) {
+    self.history.push_back(ch);
+    while self.history.len() > self.rewind_window {
+      self.history.pop_front();
+    }
+  }
+
+  ///
+  /// Whether another character is available without blocking forever on
+  /// an exhausted reader.
+  ///
+  /// # Examples
+  /// ```
+  /// use text_reader::StreamReader;
+  /// let mut reader = StreamReader::from_reader("a".as_bytes());
+  /// assert_eq!(true, reader.has_next());
+  /// reader.next();
+  /// assert_eq!(false, reader.has_next());
+  /// ```
+  pub fn has_next(&mut self) -> bool {
+    self.fill_lookahead();
+    !self.lookahead.is_empty()
+  }
+
+  ///
+  /// Next character, pulled from the underlying reader on demand.
+  ///
+  /// # Examples
+  /// ```
+  /// use text_reader::StreamReader;
+  /// let mut reader = StreamReader::from_reader("abc".as_bytes());
+  /// assert_eq!(Some('a'), reader.next());
+  /// ```
+  // Mirrors TextReader's own next()/back() naming; not a std Iterator.
+  #[allow(clippy::should_implement_trait)]
+  pub fn next(&mut self) -> Option<char> {
+    self.fill_lookahead();
+    let ch = self.lookahead.pop_front()?;
+
+    self.push_history(ch);
+    self.position += 1;
+    self.cursor += 1;
+
+    if ch == '\n' {
+      self.line += 1;
+      self.cursor = 0;
+    }
+
+    Some(ch)
+  }
+
+  ///
+  /// Back to the previous character. Returns `RewindWindowExceeded` if the
+  /// caller tries to rewind past the retained window.
+  ///
+  /// # Examples
+  /// ```
+  /// use text_reader::StreamReader;
+  /// let mut reader = StreamReader::from_reader("abc".as_bytes());
+  /// reader.next();
+  /// assert!(reader.back().is_ok());
+  /// ```
+  pub fn back(&mut self) -> Result<(), RewindWindowExceeded> {
+    let ch = match self.history.pop_back() {
+      Some(ch) => ch,
+      None => return Err(RewindWindowExceeded),
+    };
+
+    self.lookahead.push_front(ch);
+    self.position -= 1;
+    self.cursor -= 1;
+
+    if ch == '\n' {
+      self.line -= 1;
+
+      let mut distance = 0;
+      for back_ch in self.history.iter().rev() {
+        if *back_ch == '\n' {
+          break;
+        }
+        distance += 1;
+      }
+      self.cursor = distance;
+    }
+
+    Ok(())
+  }
+
+  /// Position in characters consumed so far.
+  pub fn position(&self) -> usize {
+    self.position
+  }
+
+  /// Current text line number.
+  pub fn line(&self) -> usize {
+    self.line
+  }
+
+  /// Current line position, in characters.
+  pub fn cursor(&self) -> usize {
+    self.cursor as usize
+  }
+
+  /// Bytes decoded from the underlying reader so far. The total length of
+  /// a streamed input is not known up front.
+  pub fn len(&self) -> usize {
+    self.bytes_seen
+  }
+
+  /// Whether no bytes have been decoded from the underlying reader yet.
+  pub fn is_empty(&self) -> bool {
+    self.bytes_seen == 0
+  }
+
+  ///
+  /// Current line string, as far as it is still within the retained window.
+  ///
+  /// # Examples
+  /// ```
+  /// use text_reader::StreamReader;
+  /// let mut reader = StreamReader::from_reader("abc\ndef".as_bytes());
+  /// reader.next();
+  /// assert_eq!(Some("abc".to_string()), reader.this_line());
+  /// ```
+  pub fn this_line(&mut self) -> Option<String> {
+    let original_position = self.position;
+
+    while self.cursor > 0 {
+      if self.back().is_err() {
+        break;
+      }
+    }
+    let start_position = self.position;
+
+    let mut line_text = String::new();
+    loop {
+      match self.next() {
+        Some('\n') => {
+          let _ = self.back();
+          break;
+        }
+        Some(ch) => line_text.push(ch),
+        None => break,
+      }
+    }
+
+    let is_empty = self.position == start_position;
+
+    let drift = self.position - original_position;
+    for _ in 0..drift {
+      if self.back().is_err() {
+        break;
+      }
+    }
+
+    if is_empty {
+      None
+    } else {
+      Some(line_text)
+    }
+  }
+}
+
+impl<R: Read> CharSource for StreamReader<R> {
+  fn next(&mut self) -> Option<char> {
+    StreamReader::next(self)
+  }
+
+  fn back(&mut self) -> bool {
+    StreamReader::back(self).is_ok()
+  }
+
+  fn has_next(&mut self) -> bool {
+    StreamReader::has_next(self)
+  }
+
+  fn remaining_text(&mut self) -> String {
+    StreamReader::remaining_text(self)
+  }
+}
@@ -1,5 +1,6 @@
 use std::ffi::OsStr;
 use crate::Detector;
+use crate::GraphemeReader;
 
 #[derive(Debug)]
 pub struct TextReader {
@@ -10,6 +11,52 @@ pub struct TextReader {
   cursor: isize,
 }
 
+/// Seek position, borrowed from `std::io::SeekFrom`
+///
+/// # Examples
+/// ```
+/// use text_reader::{TextReader, SeekFrom};
+/// let mut reader = TextReader::new("abc\ndef");
+/// let position = reader.seek(SeekFrom::Start(4));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeekFrom {
+  Start(usize),
+  Current(isize),
+  End(isize),
+}
+
+/// Word boundary style for `next_word`/`prev_word`, borrowed from rustyline's
+/// `Word`/`Movement` abstractions.
+///
+/// # Examples
+/// ```
+/// use text_reader::{TextReader, WordStyle};
+/// let mut reader = TextReader::new("foo, bar");
+/// let word = reader.next_word(WordStyle::Emacs);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WordStyle {
+  /// A word is a maximal run of non-whitespace characters.
+  Big,
+  /// A word is a maximal run of alphanumeric characters; everything
+  /// in between is treated as a separator.
+  Emacs,
+  /// A word is a maximal run of alphanumeric/underscore characters, or a
+  /// maximal run of other non-whitespace (punctuation) characters.
+  Vi,
+}
+
+fn vi_class(ch: char) -> u8 {
+  if ch.is_whitespace() {
+    0
+  } else if ch.is_alphanumeric() || ch == '_' {
+    1
+  } else {
+    2
+  }
+}
+
 ///
 /// Text character reader.
 ///
@@ -39,6 +86,19 @@ impl TextReader {
     }
   }
 
+  ///
+  /// Create a grapheme-cluster aware reader over the same text, so emoji and
+  /// combining marks are read back whole instead of split across `char`s.
+  ///
+  /// # Examples
+  /// ```
+  /// use text_reader::TextReader;
+  /// let mut reader = TextReader::new_graphemes("abc\ndef");
+  /// ```
+  pub fn new_graphemes<S: AsRef<OsStr>>(text: S) -> GraphemeReader {
+    GraphemeReader::new(text)
+  }
+
   ///
   /// Detect possible strings
   ///
@@ -49,7 +109,7 @@ impl TextReader {
   /// let mut detector = reader.detector();
   ///
   /// ```
-  pub fn detector(&mut self) -> Detector {
+  pub fn detector(&mut self) -> Detector<TextReader> {
     Detector::new(self)
   }
 
@@ -266,6 +326,323 @@ impl TextReader {
     Some(line_text)
   }
 
+  ///
+  /// Seek to a position, recomputing `line` and `cursor` for that position.
+  /// Returns `None` if the resulting offset is out of `0..=len`.
+  ///
+  /// # Examples
+  /// ```
+  /// use text_reader::{TextReader, SeekFrom};
+  /// let mut reader = TextReader::new("abc\ndef");
+  /// let position = reader.seek(SeekFrom::Start(4));
+  /// assert_eq!(Some(4), position);
+  /// assert_eq!(2, reader.line());
+  /// assert_eq!(0, reader.cursor());
+  /// ```
+  pub fn seek(&mut self, pos: SeekFrom) -> Option<usize> {
+    let target = match pos {
+      SeekFrom::Start(offset) => offset as isize,
+      SeekFrom::Current(offset) => (self.position as isize).checked_add(offset)?,
+      SeekFrom::End(offset) => (self.len as isize).checked_add(offset)?,
+    };
+
+    if target < 0 || target as usize > self.len {
+      return None;
+    }
+    let position = target as usize;
+
+    let mut line = 1;
+    let mut last_newline = None;
+    for (ix, ch) in self.text.iter().enumerate().take(position) {
+      if *ch == '\n' {
+        line += 1;
+        last_newline = Some(ix);
+      }
+    }
+
+    self.position = position;
+    self.line = line;
+    self.cursor = match last_newline {
+      Some(ix) => (position - ix - 1) as isize,
+      None => position as isize,
+    };
+
+    Some(self.position)
+  }
+
+  fn peek_current(&self) -> Option<char> {
+    self.text.get(self.position).cloned()
+  }
+
+  pub(crate) fn remaining_text(&self) -> String {
+    self.text[self.position..].iter().collect()
+  }
+
+  ///
+  /// Match a regex pattern anchored at the current position, advancing past
+  /// the match on success.
+  ///
+  /// # Examples
+  /// ```
+  /// use text_reader::TextReader;
+  /// let mut reader = TextReader::new("123abc");
+  /// assert_eq!(Some("123".to_string()), reader.matches_regex(r"\d+"));
+  /// ```
+  pub fn matches_regex(&mut self, pattern: &str) -> Option<String> {
+    let mut detector = self.detector();
+    if detector.next_regex(pattern).yes() {
+      detector.matched().map(|m| m.to_string())
+    } else {
+      None
+    }
+  }
+
+  ///
+  /// Read the next word, advancing `position` past it according to `style`.
+  /// Leading separators are skipped first. Returns `None` at EOF.
+  ///
+  /// # Examples
+  /// ```
+  /// use text_reader::{TextReader, WordStyle};
+  /// let mut reader = TextReader::new("foo, bar");
+  /// assert_eq!(Some("foo".to_string()), reader.next_word(WordStyle::Emacs));
+  /// assert_eq!(Some("bar".to_string()), reader.next_word(WordStyle::Emacs));
+  /// ```
+  pub fn next_word(&mut self, style: WordStyle) -> Option<String> {
+    match style {
+      WordStyle::Big => {
+        while let Some(ch) = self.peek_current() {
+          if !ch.is_whitespace() {
+            break;
+          }
+          self.next();
+        }
+      }
+      WordStyle::Emacs => {
+        while let Some(ch) = self.peek_current() {
+          if ch.is_alphanumeric() {
+            break;
+          }
+          self.next();
+        }
+      }
+      WordStyle::Vi => {
+        while let Some(ch) = self.peek_current() {
+          if !ch.is_whitespace() {
+            break;
+          }
+          self.next();
+        }
+      }
+    }
+
+    let first = self.peek_current()?;
+
+    let mut word = String::new();
+    match style {
+      WordStyle::Big => {
+        while let Some(ch) = self.peek_current() {
+          if ch.is_whitespace() {
+            break;
+          }
+          word.push(self.next().unwrap());
+        }
+      }
+      WordStyle::Emacs => {
+        while let Some(ch) = self.peek_current() {
+          if !ch.is_alphanumeric() {
+            break;
+          }
+          word.push(self.next().unwrap());
+        }
+      }
+      WordStyle::Vi => {
+        let class = vi_class(first);
+        while let Some(ch) = self.peek_current() {
+          if vi_class(ch) != class {
+            break;
+          }
+          word.push(self.next().unwrap());
+        }
+      }
+    }
+
+    Some(word)
+  }
+
+  ///
+  /// Read the previous word, retreating `position` across it according to
+  /// `style`. Trailing separators are skipped first. Returns `None` at BOF.
+  ///
+  /// # Examples
+  /// ```
+  /// use text_reader::{TextReader, WordStyle};
+  /// let mut reader = TextReader::new("foo, bar");
+  /// while reader.has_next() {
+  ///   reader.next();
+  /// }
+  /// assert_eq!(Some("bar".to_string()), reader.prev_word(WordStyle::Emacs));
+  /// assert_eq!(Some("foo".to_string()), reader.prev_word(WordStyle::Emacs));
+  /// ```
+  pub fn prev_word(&mut self, style: WordStyle) -> Option<String> {
+    match style {
+      WordStyle::Big => {
+        while let Some(ch) = self.peek() {
+          if !ch.is_whitespace() {
+            break;
+          }
+          self.back();
+        }
+      }
+      WordStyle::Emacs => {
+        while let Some(ch) = self.peek() {
+          if ch.is_alphanumeric() {
+            break;
+          }
+          self.back();
+        }
+      }
+      WordStyle::Vi => {
+        while let Some(ch) = self.peek() {
+          if !ch.is_whitespace() {
+            break;
+          }
+          self.back();
+        }
+      }
+    }
+
+    let last = self.peek()?;
+
+    let mut word = String::new();
+    match style {
+      WordStyle::Big => {
+        while let Some(ch) = self.peek() {
+          if ch.is_whitespace() {
+            break;
+          }
+          self.back();
+          word.push(ch);
+        }
+      }
+      WordStyle::Emacs => {
+        while let Some(ch) = self.peek() {
+          if !ch.is_alphanumeric() {
+            break;
+          }
+          self.back();
+          word.push(ch);
+        }
+      }
+      WordStyle::Vi => {
+        let class = vi_class(last);
+        while let Some(ch) = self.peek() {
+          if vi_class(ch) != class {
+            break;
+          }
+          self.back();
+          word.push(ch);
+        }
+      }
+    }
+
+    Some(word.chars().rev().collect())
+  }
+
+  ///
+  /// Consume and return the maximal run of characters satisfying `pred`,
+  /// stopping (without consuming) at the first non-match or EOF.
+  ///
+  /// # Examples
+  /// ```
+  /// use text_reader::TextReader;
+  /// let mut reader = TextReader::new("123abc");
+  /// assert_eq!("123".to_string(), reader.read_while(|ch| ch.is_numeric()));
+  /// assert_eq!(Some('a'), reader.next());
+  /// ```
+  pub fn read_while<F: Fn(char) -> bool>(&mut self, pred: F) -> String {
+    let mut text = String::new();
+    while let Some(ch) = self.peek_current() {
+      if !pred(ch) {
+        break;
+      }
+      text.push(self.next().unwrap());
+    }
+    text
+  }
+
+  ///
+  /// Like `read_while`, but discards the consumed characters.
+  ///
+  /// # Examples
+  /// ```
+  /// use text_reader::TextReader;
+  /// let mut reader = TextReader::new("   abc");
+  /// reader.skip_while(|ch| ch.is_whitespace());
+  /// assert_eq!(Some('a'), reader.next());
+  /// ```
+  pub fn skip_while<F: Fn(char) -> bool>(&mut self, pred: F) {
+    while let Some(ch) = self.peek_current() {
+      if !pred(ch) {
+        break;
+      }
+      self.next();
+    }
+  }
+
+  ///
+  /// Consume up to (but not including) the first occurrence of `ch`,
+  /// returning the text in between. Leaves the cursor positioned at `ch`.
+  /// Returns `None` if `ch` is never found before EOF.
+  ///
+  /// # Examples
+  /// ```
+  /// use text_reader::TextReader;
+  /// let mut reader = TextReader::new("key=value");
+  /// assert_eq!(Some("key".to_string()), reader.read_until('='));
+  /// assert_eq!(Some('='), reader.next());
+  /// ```
+  pub fn read_until(&mut self, ch: char) -> Option<String> {
+    let start = self.position;
+    let text = self.read_while(|c| c != ch);
+    if self.peek_current().is_none() {
+      self.seek(SeekFrom::Start(start));
+      return None;
+    }
+    Some(text)
+  }
+
+  ///
+  /// Consume up to (but not including) the first occurrence of `text`,
+  /// returning the text in between. Leaves the cursor positioned right
+  /// before `text`. Returns `None` if `text` is never found before EOF.
+  ///
+  /// # Examples
+  /// ```
+  /// use text_reader::TextReader;
+  /// let mut reader = TextReader::new("foo::bar");
+  /// assert_eq!(Some("foo".to_string()), reader.read_until_text("::"));
+  /// ```
+  pub fn read_until_text<S: AsRef<OsStr>>(&mut self, text: S) -> Option<String> {
+    let needle = text.as_ref().to_str().unwrap();
+    if needle.is_empty() {
+      return Some(String::new());
+    }
+
+    let start = self.position;
+    let mut result = String::new();
+
+    while self.has_next() {
+      if self.remaining_text().starts_with(needle) {
+        return Some(result);
+      }
+      result.push(self.next().unwrap());
+    }
+
+    self.seek(SeekFrom::Start(start));
+    None
+  }
+
   ///
   /// Has next character
   ///
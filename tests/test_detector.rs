@@ -31,3 +31,26 @@ fn test_detector() {
 
 }
 
+#[test]
+fn test_detector_next_regex() {
+  let mut reader = TextReader::new("123abc");
+  let mut detector = reader.detector();
+
+  assert_eq!(true, detector.next_regex(r"(\d+)([a-z]+)").yes());
+  assert_eq!(Some("123abc"), detector.matched());
+  assert_eq!(Some("123"), detector.group(1));
+  assert_eq!(Some("abc"), detector.group(2));
+  assert_eq!(None, detector.group(0));
+  assert_eq!(None, detector.group(3));
+
+  assert_eq!(false, reader.has_next());
+}
+
+#[test]
+fn test_matches_regex() {
+  let mut reader = TextReader::new("42 is the answer");
+  assert_eq!(Some("42".to_string()), reader.matches_regex(r"\d+"));
+  assert_eq!(Some(' '), reader.next());
+  assert_eq!(None, reader.matches_regex(r"\d+"));
+}
+
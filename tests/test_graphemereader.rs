@@ -0,0 +1,35 @@
+use text_reader::{GraphemeReader, TextReader};
+
+#[test]
+fn test_grapheme_reader() {
+  let mut reader = GraphemeReader::new("e\u{301}\u{1f1fa}\u{1f1f8}\ndef");
+
+  assert_eq!(6, reader.len());
+  assert_eq!(Some("e\u{301}".to_string()), reader.next());
+  assert_eq!(Some("\u{1f1fa}\u{1f1f8}".to_string()), reader.next());
+  assert_eq!(2, reader.position());
+  assert_eq!(2, reader.cursor());
+  assert_eq!(Some("\n".to_string()), reader.next());
+  assert_eq!(2, reader.line());
+  assert_eq!(0, reader.cursor());
+  assert_eq!(Some("d".to_string()), reader.next());
+  assert_eq!(Some("e".to_string()), reader.next());
+  assert_eq!(Some("f".to_string()), reader.next());
+  assert_eq!(false, reader.has_next());
+}
+
+#[test]
+fn test_grapheme_reader_this_line() {
+  let mut reader = GraphemeReader::new("e\u{301}\u{1f1fa}\u{1f1f8}\ndef");
+  reader.next();
+  reader.next();
+  assert_eq!(Some("e\u{301}\u{1f1fa}\u{1f1f8}".to_string()), reader.this_line());
+}
+
+#[test]
+fn test_new_graphemes_from_text_reader() {
+  let mut reader = TextReader::new_graphemes("e\u{301}f");
+  assert_eq!(2, reader.len());
+  assert_eq!(Some("e\u{301}".to_string()), reader.next());
+  assert_eq!(Some("f".to_string()), reader.next());
+}
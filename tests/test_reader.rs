@@ -1,4 +1,4 @@
-use text_reader::TextReader;
+use text_reader::{TextReader, SeekFrom, WordStyle};
 
 #[test]
 fn test_reader() {
@@ -45,6 +45,105 @@ fn test_while() {
   }
 }
 
+#[test]
+fn test_seek() {
+  let mut reader = TextReader::new("華文\ndef");
+
+  assert_eq!(Some(4), reader.seek(SeekFrom::Start(4)));
+  assert_eq!(2, reader.line());
+  assert_eq!(1, reader.cursor());
+  assert_eq!(Some('e'), reader.next());
+
+  assert_eq!(Some(1), reader.seek(SeekFrom::Current(-4)));
+  assert_eq!(1, reader.line());
+  assert_eq!(1, reader.cursor());
+  assert_eq!(Some('文'), reader.next());
+
+  assert_eq!(Some(6), reader.seek(SeekFrom::End(0)));
+  assert_eq!(false, reader.has_next());
+
+  assert_eq!(None, reader.seek(SeekFrom::Start(7)));
+  assert_eq!(None, reader.seek(SeekFrom::Current(-100)));
+}
+
+#[test]
+fn test_seek_overflow() {
+  let mut reader = TextReader::new("abc");
+
+  assert_eq!(None, reader.seek(SeekFrom::Current(isize::MAX)));
+  assert_eq!(None, reader.seek(SeekFrom::End(isize::MAX)));
+  assert_eq!(0, reader.position());
+}
+
+#[test]
+fn test_next_word() {
+  let mut reader = TextReader::new("foo, bar-baz  qux");
+
+  assert_eq!(Some("foo".to_string()), reader.next_word(WordStyle::Emacs));
+  assert_eq!(Some("bar".to_string()), reader.next_word(WordStyle::Emacs));
+  assert_eq!(Some("baz".to_string()), reader.next_word(WordStyle::Emacs));
+  assert_eq!(Some("qux".to_string()), reader.next_word(WordStyle::Emacs));
+  assert_eq!(None, reader.next_word(WordStyle::Emacs));
+
+  reader.reset();
+  assert_eq!(Some("foo,".to_string()), reader.next_word(WordStyle::Big));
+  assert_eq!(Some("bar-baz".to_string()), reader.next_word(WordStyle::Big));
+  assert_eq!(Some("qux".to_string()), reader.next_word(WordStyle::Big));
+
+  reader.reset();
+  assert_eq!(Some("foo".to_string()), reader.next_word(WordStyle::Vi));
+  assert_eq!(Some(",".to_string()), reader.next_word(WordStyle::Vi));
+  assert_eq!(Some("bar".to_string()), reader.next_word(WordStyle::Vi));
+  assert_eq!(Some("-".to_string()), reader.next_word(WordStyle::Vi));
+  assert_eq!(Some("baz".to_string()), reader.next_word(WordStyle::Vi));
+  assert_eq!(Some("qux".to_string()), reader.next_word(WordStyle::Vi));
+}
+
+#[test]
+fn test_prev_word() {
+  let mut reader = TextReader::new("foo, bar-baz  qux");
+  while reader.has_next() {
+    reader.next();
+  }
+
+  assert_eq!(Some("qux".to_string()), reader.prev_word(WordStyle::Emacs));
+  assert_eq!(Some("baz".to_string()), reader.prev_word(WordStyle::Emacs));
+  assert_eq!(Some("bar".to_string()), reader.prev_word(WordStyle::Emacs));
+  assert_eq!(Some("foo".to_string()), reader.prev_word(WordStyle::Emacs));
+  assert_eq!(None, reader.prev_word(WordStyle::Emacs));
+}
+
+#[test]
+fn test_read_while_and_skip_while() {
+  let mut reader = TextReader::new("123   abc");
+
+  assert_eq!("123".to_string(), reader.read_while(|ch| ch.is_numeric()));
+  reader.skip_while(|ch| ch.is_whitespace());
+  assert_eq!("abc".to_string(), reader.read_while(|ch| ch.is_alphabetic()));
+  assert_eq!(false, reader.has_next());
+}
+
+#[test]
+fn test_read_until() {
+  let mut reader = TextReader::new("key=value");
+
+  assert_eq!(Some("key".to_string()), reader.read_until('='));
+  assert_eq!(Some('='), reader.next());
+  assert_eq!(None, reader.read_until(';'));
+  assert_eq!("value".to_string(), reader.read_while(|_| true));
+}
+
+#[test]
+fn test_read_until_text() {
+  let mut reader = TextReader::new("foo::bar::baz");
+
+  assert_eq!(Some("foo".to_string()), reader.read_until_text("::"));
+  assert_eq!(Some(':'), reader.next());
+  assert_eq!(Some(':'), reader.next());
+  assert_eq!(Some("bar".to_string()), reader.read_until_text("::"));
+  assert_eq!(None, reader.read_until_text("nope"));
+}
+
 #[test]
 fn test_stat() {
   let mut reader = TextReader::new("abc\ndef");
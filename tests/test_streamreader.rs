@@ -0,0 +1,74 @@
+use text_reader::StreamReader;
+
+#[test]
+fn test_stream_reader() {
+  let mut reader = StreamReader::from_reader("abc\ndef".as_bytes());
+
+  assert_eq!(Some('a'), reader.next());
+  assert_eq!(Some('b'), reader.next());
+  assert_eq!(Some('c'), reader.next());
+  assert_eq!(3, reader.position());
+  assert_eq!(Some('\n'), reader.next());
+  assert_eq!(2, reader.line());
+  assert_eq!(0, reader.cursor());
+  assert_eq!(Some('d'), reader.next());
+  assert_eq!(Some('e'), reader.next());
+  assert_eq!(Some('f'), reader.next());
+  assert_eq!(false, reader.has_next());
+}
+
+#[test]
+fn test_stream_reader_back_and_this_line() {
+  let mut reader = StreamReader::from_reader("abc\ndef".as_bytes());
+  reader.next();
+  reader.next();
+  assert_eq!(Some("abc".to_string()), reader.this_line());
+  assert_eq!(2, reader.position());
+
+  assert!(reader.back().is_ok());
+  assert_eq!(Some('b'), reader.next());
+}
+
+#[test]
+fn test_stream_reader_rewind_window_exceeded() {
+  let mut reader = StreamReader::with_rewind_window("abcdef".as_bytes(), 2);
+  reader.next();
+  reader.next();
+  reader.next();
+
+  assert!(reader.back().is_ok());
+  assert!(reader.back().is_ok());
+  assert!(reader.back().is_err());
+}
+
+#[test]
+fn test_stream_reader_detector() {
+  let mut reader = StreamReader::from_reader("123abc".as_bytes());
+  let mut detector = reader.detector();
+
+  assert_eq!(true, detector.next_regex(r"\d+").yes());
+  assert_eq!(Some("123"), detector.matched());
+
+  assert_eq!(Some('a'), reader.next());
+}
+
+#[test]
+fn test_stream_reader_detector_rollback() {
+  let mut reader = StreamReader::from_reader("abcdef".as_bytes());
+  let mut detector = reader.detector();
+
+  assert_eq!(true, detector.next_text("abc").yes());
+  detector.rollback();
+
+  assert_eq!(Some('a'), reader.next());
+}
+
+#[test]
+#[should_panic(expected = "rewind window")]
+fn test_stream_reader_detector_rollback_past_rewind_window() {
+  let mut reader = StreamReader::with_rewind_window("abcdXYZ".as_bytes(), 2);
+  let mut detector = reader.detector();
+
+  assert_eq!(true, detector.next_text("abcd").yes());
+  detector.rollback();
+}